@@ -8681,6 +8681,11 @@ pub struct ListMethodsForPaymentsRequest {
     /// Indicates the limit of last used payment methods
     #[schema(example = 1)]
     pub limit: Option<i64>,
+
+    /// Indicates whether the split payment preview computed from the gift card balances
+    /// checked for this payment should be returned
+    #[schema(example = true)]
+    pub include_split_preview: Option<bool>,
 }
 
 #[cfg(feature = "v2")]
@@ -8693,6 +8698,30 @@ pub struct PaymentMethodListResponseForPayments {
     /// This field is only returned if the customer_id is provided in the request
     #[schema(value_type = Option<Vec<CustomerPaymentMethodResponseItem>>)]
     pub customer_payment_methods: Option<Vec<payment_methods::CustomerPaymentMethodResponseItem>>,
+
+    /// Preview of how the order amount would be split between the gift cards whose balance
+    /// was checked for this payment and the remaining payment method
+    /// This field is only returned if `include_split_preview` is true in the request
+    pub split_payment_preview: Option<SplitPaymentPreview>,
+}
+
+#[cfg(feature = "v2")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema, PartialEq)]
+pub struct SplitPaymentPreview {
+    /// Amount the gift cards checked for this payment can contribute, i.e. the sum of their
+    /// balances each capped by its per transaction limit. Only gift cards with a balance in the
+    /// payment currency are considered
+    pub gift_card_balance: MinorUnit,
+
+    /// Amount that would be charged from the gift cards for the current order amount
+    pub gift_card_contribution: MinorUnit,
+
+    /// Amount that would have to be paid with another payment method
+    pub remaining_amount: MinorUnit,
+
+    /// The currency of the amounts
+    #[schema(value_type = Currency)]
+    pub currency: common_enums::Currency,
 }
 
 #[cfg(feature = "v2")]
@@ -9538,6 +9567,70 @@ mod payments_request_api_contract {
     }
 }
 
+#[cfg(feature = "v2")]
+#[cfg(test)]
+mod split_payment_method_data_request_api_contract {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    const GIFT_CARD_SPLIT_REQUEST: &str = r#"
+    {
+        "payment_method_data": {
+            "gift_card": {
+                "givex": {
+                    "number": "6364530000000000",
+                    "cvc": "122222"
+                }
+            }
+        },
+        "payment_method_type": "gift_card",
+        "payment_method_subtype": "givex"
+    }
+    "#;
+
+    const CARD_SPLIT_REQUEST: &str = r#"
+    {
+        "payment_method_data": {
+            "card": {
+                "card_number": "4242424242424242",
+                "card_exp_month": "10",
+                "card_exp_year": "35",
+                "card_holder_name": "joseph Doe",
+                "card_cvc": "123"
+            }
+        },
+        "payment_method_type": "card",
+        "payment_method_subtype": "credit"
+    }
+    "#;
+
+    #[test]
+    fn test_split_request_round_trip() {
+        for split_request in [GIFT_CARD_SPLIT_REQUEST, CARD_SPLIT_REQUEST] {
+            let original: serde_json::Value = serde_json::from_str(split_request).unwrap();
+            let deserialized =
+                serde_json::from_value::<SplitPaymentMethodDataRequest>(original.clone()).unwrap();
+            let reserialized = serde_json::to_value(&deserialized).unwrap();
+
+            assert_eq!(
+                reserialized.get("payment_method_type"),
+                original.get("payment_method_type")
+            );
+            assert_eq!(
+                reserialized.get("payment_method_subtype"),
+                original.get("payment_method_subtype")
+            );
+
+            let redeserialized =
+                serde_json::from_value::<SplitPaymentMethodDataRequest>(reserialized).unwrap();
+            assert_eq!(
+                redeserialized.payment_method_data,
+                deserialized.payment_method_data
+            );
+        }
+    }
+}
+
 #[cfg(test)]
 mod payments_response_api_contract {
     #![allow(clippy::unwrap_used)]
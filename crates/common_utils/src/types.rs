@@ -408,6 +408,11 @@ impl MinorUnit {
         self.get_amount_as_i64() > value
     }
 
+    /// adds two amounts, returning `None` if the addition overflows
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
     /// Convert the amount to its major denomination based on Currency and return String
     /// Paypal Connector accepts Zero and Two decimal currency but not three decimal and it should be updated as required for 3 decimal currencies.
     /// Paypal Ref - https://developer.paypal.com/docs/reports/reference/paypal-supported-currencies/
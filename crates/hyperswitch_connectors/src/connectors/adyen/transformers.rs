@@ -381,6 +381,7 @@ pub struct AdyenBalanceRequest<'a> {
 pub struct AdyenBalanceResponse {
     pub psp_reference: String,
     pub balance: Amount,
+    pub transaction_limit: Option<Amount>,
 }
 
 /// This implementation will be used only in Authorize, Automatic capture flow.
@@ -4074,6 +4075,10 @@ impl
             response: Ok(GiftCardBalanceCheckResponseData {
                 balance: item.response.balance.value,
                 currency: item.response.balance.currency,
+                transaction_limit: item
+                    .response
+                    .transaction_limit
+                    .map(|transaction_limit| transaction_limit.value),
             }),
             ..item.data
         })
@@ -167,6 +167,8 @@ pub enum ApiErrorResponse {
     InvalidTenant { tenant_id: String },
     #[error(error_type = ErrorType::ValidationError, code = "HE_06", message = "Failed to convert amount to {amount_type} type")]
     AmountConversionFailed { amount_type: &'static str },
+    #[error(error_type = ErrorType::ValidationError, code = "HE_06", message = "Overflow while computing {amount_type}")]
+    AmountOverflow { amount_type: &'static str },
     #[error(error_type = ErrorType::ServerNotAvailable, code = "IR_00", message = "{message:?}")]
     NotImplemented { message: NotImplementedMessage },
     #[error(
@@ -520,6 +522,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::AmountConversionFailed { amount_type }  => {
                 AER::InternalServerError(ApiError::new("HE", 6, format!("Failed to convert amount to {amount_type} type"), None))
             }
+            Self::AmountOverflow { amount_type }  => {
+                AER::InternalServerError(ApiError::new("HE", 6, format!("Overflow while computing {amount_type}"), None))
+            }
 
             Self::NotImplemented { message } => {
                 AER::NotImplemented(ApiError::new("IR", 0, format!("{message:?}"), None))
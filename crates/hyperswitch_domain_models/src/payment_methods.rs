@@ -1207,6 +1207,19 @@ impl PaymentMethodBalanceKey {
 pub struct PaymentMethodBalance {
     pub balance: common_utils::types::MinorUnit,
     pub currency: common_enums::Currency,
+    pub transaction_limit: Option<common_utils::types::MinorUnit>,
+}
+
+#[cfg(feature = "v2")]
+impl PaymentMethodBalance {
+    /// Amount that can be contributed by this payment method, i.e. the balance capped by
+    /// the per transaction limit
+    pub fn get_available_balance(&self) -> common_utils::types::MinorUnit {
+        match self.transaction_limit {
+            Some(transaction_limit) => self.balance.min(transaction_limit),
+            None => self.balance,
+        }
+    }
 }
 
 #[cfg(feature = "v2")]
@@ -1224,6 +1237,8 @@ impl<'a> PaymentMethodBalanceData<'a> {
         }
     }
 
+    /// Balances are cached per payment. There is no merchant in the key, the entries of different
+    /// merchants are kept apart because a global payment id belongs to a single merchant
     pub fn get_pm_balance_redis_key(&self) -> String {
         format!("pm_balance_{}", self.payment_intent_id.get_string_repr())
     }
@@ -1408,3 +1423,40 @@ mod tests {
         assert!(common_mandate.payouts.is_some());
     }
 }
+
+#[cfg(feature = "v2")]
+#[cfg(test)]
+mod payment_method_balance_tests {
+    use common_utils::types::MinorUnit;
+
+    use super::*;
+
+    fn get_payment_method_balance(
+        balance: i64,
+        transaction_limit: Option<i64>,
+    ) -> PaymentMethodBalance {
+        PaymentMethodBalance {
+            balance: MinorUnit::new(balance),
+            currency: common_enums::Currency::USD,
+            transaction_limit: transaction_limit.map(MinorUnit::new),
+        }
+    }
+
+    #[test]
+    fn test_available_balance_without_transaction_limit() {
+        let pm_balance = get_payment_method_balance(5000, None);
+        assert_eq!(pm_balance.get_available_balance(), MinorUnit::new(5000));
+    }
+
+    #[test]
+    fn test_available_balance_capped_by_transaction_limit() {
+        let pm_balance = get_payment_method_balance(5000, Some(3000));
+        assert_eq!(pm_balance.get_available_balance(), MinorUnit::new(3000));
+    }
+
+    #[test]
+    fn test_available_balance_when_transaction_limit_exceeds_balance() {
+        let pm_balance = get_payment_method_balance(1500, Some(3000));
+        assert_eq!(pm_balance.get_available_balance(), MinorUnit::new(1500));
+    }
+}
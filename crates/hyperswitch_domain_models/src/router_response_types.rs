@@ -117,6 +117,9 @@ pub enum PaymentsResponseData {
 pub struct GiftCardBalanceCheckResponseData {
     pub balance: MinorUnit,
     pub currency: common_enums::Currency,
+    /// Maximum amount that can be charged on the gift card in a single transaction, if the
+    /// connector enforces one
+    pub transaction_limit: Option<MinorUnit>,
 }
 
 #[derive(Debug, Clone)]
@@ -563,6 +563,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentMethodBalanceCheckRequest,
         api_models::payments::ApplyPaymentMethodDataRequest,
         api_models::payments::ApplyPaymentMethodDataResponse,
+        api_models::payments::SplitPaymentPreview,
         api_models::payments::ApplyPaymentMethodDataSurchargeResponseItem,
         api_models::enums::PaymentConnectorTransmission,
         api_models::enums::TriggeredBy,
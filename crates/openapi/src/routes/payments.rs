@@ -1278,6 +1278,7 @@ pub(crate) enum ForceSync {
           description = "Profile ID associated to the payment intent",
           example = "pro_abcdefghijklmnop"
         ),
+        ("include_split_preview" = Option<bool>, Query, description = "Whether to return the split payment preview computed from the gift card balances checked for this payment"),
     ),
     responses(
         (status = 200, description = "Get the payment methods", body = PaymentMethodListResponseForPayments),
@@ -516,6 +516,7 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             | errors::ApiErrorResponse::MandateSerializationFailed
             | errors::ApiErrorResponse::MandateDeserializationFailed
             | errors::ApiErrorResponse::InternalServerError
+            | errors::ApiErrorResponse::AmountOverflow { .. }
             | errors::ApiErrorResponse::HealthCheckError { .. } => Self::InternalServerError, // not a stripe code
             errors::ApiErrorResponse::ExternalConnectorError {
                 code,
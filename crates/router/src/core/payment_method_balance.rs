@@ -2,7 +2,7 @@ use std::{collections::HashMap, marker::PhantomData};
 
 use api_models::payments::{
     ApplyPaymentMethodDataRequest, ApplyPaymentMethodDataResponse, GetPaymentMethodType,
-    PaymentMethodBalanceCheckRequest, PaymentMethodBalanceCheckResponse,
+    PaymentMethodBalanceCheckRequest, PaymentMethodBalanceCheckResponse, SplitPaymentPreview,
 };
 use common_enums::CallConnectorAction;
 use common_utils::{
@@ -168,22 +168,15 @@ pub async fn payments_check_gift_card_balance_core(
     let balance = gift_card_balance.balance;
     let currency = gift_card_balance.currency;
 
-    let payment_method_key = domain::GiftCardData::from(gift_card_data.clone())
-        .get_payment_method_key()
-        .change_context(errors::ApiErrorResponse::InvalidRequestData {
-            message: "Unable to get unique key for payment method".to_string(),
-        })?
-        .expose();
-
     let balance_data = domain::PaymentMethodBalanceData {
         payment_intent_id: &payment_intent.id,
         pm_balance_data: vec![(
-            domain::PaymentMethodBalanceKey {
-                payment_method_type: common_enums::PaymentMethod::GiftCard,
-                payment_method_subtype: gift_card_data.get_payment_method_type(),
-                payment_method_key,
+            get_gift_card_balance_key(&gift_card_data)?,
+            domain::PaymentMethodBalance {
+                balance,
+                currency,
+                transaction_limit: gift_card_balance.transaction_limit,
             },
-            domain::PaymentMethodBalance { balance, currency },
         )]
         .into_iter()
         .collect(),
@@ -202,6 +195,24 @@ pub async fn payments_check_gift_card_balance_core(
     Ok(services::ApplicationResponse::Json(resp))
 }
 
+/// Generates the key identifying the gift card's balance entry in redis
+fn get_gift_card_balance_key(
+    gift_card_data: &api_models::payments::GiftCardData,
+) -> errors::RouterResult<domain::PaymentMethodBalanceKey> {
+    let payment_method_key = domain::GiftCardData::from(gift_card_data.clone())
+        .get_payment_method_key()
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Unable to get unique key for payment method".to_string(),
+        })?
+        .expose();
+
+    Ok(domain::PaymentMethodBalanceKey {
+        payment_method_type: common_enums::PaymentMethod::GiftCard,
+        payment_method_subtype: gift_card_data.get_payment_method_type(),
+        payment_method_key,
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn payments_apply_pm_data_core(
     state: SessionState,
@@ -228,21 +239,67 @@ pub async fn payments_apply_pm_data_core(
             .await
             .attach_printable("Failed to retrieve payment method balances from redis")?;
 
-    let total_balance: MinorUnit = balances.values().map(|value| value.balance).sum();
+    let resp = get_apply_pm_data_response(
+        balances.values(),
+        payment_intent.amount_details.order_amount,
+        payment_intent.amount_details.currency,
+    )?;
 
-    // remaining_amount cannot be negative, hence using max with 0. This situation can arise when
-    // the gift card balance exceeds the order amount
-    let remaining_amount =
-        (payment_intent.amount_details.order_amount - total_balance).max(MinorUnit::zero());
+    Ok(services::ApplicationResponse::Json(resp))
+}
 
-    let resp = ApplyPaymentMethodDataResponse {
+fn get_apply_pm_data_response<'a>(
+    balances: impl IntoIterator<Item = &'a domain::PaymentMethodBalance>,
+    order_amount: MinorUnit,
+    currency: common_enums::Currency,
+) -> errors::RouterResult<ApplyPaymentMethodDataResponse> {
+    let total_balance = get_total_available_balance(balances, currency)?;
+
+    let remaining_amount = get_remaining_amount(order_amount, total_balance);
+
+    Ok(ApplyPaymentMethodDataResponse {
         remaining_amount,
-        currency: payment_intent.amount_details.currency,
+        currency,
         requires_additional_pm_data: remaining_amount.is_greater_than(0),
         surcharge_details: None, // TODO: Implement surcharge recalculation logic
-    };
+    })
+}
 
-    Ok(services::ApplicationResponse::Json(resp))
+/// Sums the amount each payment method can contribute. Each payment method can only contribute
+/// up to its per transaction limit, which may be lower than its balance. Balances in a currency
+/// other than the payment currency cannot be applied to the order and are skipped
+fn get_total_available_balance<'a>(
+    balances: impl IntoIterator<Item = &'a domain::PaymentMethodBalance>,
+    currency: common_enums::Currency,
+) -> errors::RouterResult<MinorUnit> {
+    balances
+        .into_iter()
+        .filter(|pm_balance| pm_balance.currency == currency)
+        .try_fold(MinorUnit::zero(), |total_balance, pm_balance| {
+            total_balance.checked_add(pm_balance.get_available_balance())
+        })
+        .ok_or(errors::ApiErrorResponse::AmountOverflow {
+            amount_type: "total payment method balance",
+        })
+        .attach_printable("Overflow while adding up payment method balances")
+}
+
+/// Amount of the order left to be paid after applying the payment method balances. The amount
+/// before clamping is logged at debug level, a negative value there means the balances exceed
+/// the order amount
+fn get_remaining_amount(order_amount: MinorUnit, total_balance: MinorUnit) -> MinorUnit {
+    let unclamped_remaining_amount = order_amount - total_balance;
+
+    logger::debug!(
+        order_amount = order_amount.get_amount_as_i64(),
+        total_balance = total_balance.get_amount_as_i64(),
+        unclamped_remaining_amount = unclamped_remaining_amount.get_amount_as_i64(),
+        "Computed remaining amount before clamping"
+    );
+
+    // remaining_amount cannot be negative, hence using max with 0. This situation can arise when
+    // the gift card balance exceeds the order amount
+    unclamped_remaining_amount.max(MinorUnit::zero())
 }
 
 #[instrument(skip_all)]
@@ -290,11 +347,12 @@ pub async fn persist_individual_pm_balance_details_in_redis<'a>(
     Ok(())
 }
 
-pub async fn fetch_payment_methods_balances_from_redis(
+/// Fetches the balances of all the payment methods checked for the payment, keyed by the
+/// redis key of the individual payment method
+pub async fn fetch_all_payment_method_balances_from_redis(
     state: &SessionState,
     payment_intent_id: &id_type::GlobalPaymentId,
-    payment_methods: &[api_models::payments::BalanceCheckPaymentMethodData],
-) -> errors::RouterResult<HashMap<domain::PaymentMethodBalanceKey, domain::PaymentMethodBalance>> {
+) -> errors::RouterResult<HashMap<String, domain::PaymentMethodBalance>> {
     let redis_conn = state
         .store
         .get_redis_conn()
@@ -303,7 +361,7 @@ pub async fn fetch_payment_methods_balances_from_redis(
 
     let balance_data = domain::PaymentMethodBalanceData::new(payment_intent_id);
 
-    let balance_values: HashMap<String, domain::PaymentMethodBalance> = redis_conn
+    redis_conn
         .get_hash_fields::<Vec<(String, String)>>(&balance_data.get_pm_balance_redis_key().into())
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -316,22 +374,57 @@ pub async fn fetch_payment_methods_balances_from_redis(
                 .attach_printable("Failed to parse PaymentMethodBalance")
                 .map(|parsed| (key, parsed))
         })
-        .collect::<errors::RouterResult<HashMap<_, _>>>()?;
+        .collect::<errors::RouterResult<HashMap<_, _>>>()
+}
+
+/// Computes how the order amount would be split between the gift cards whose balance was
+/// checked for the payment and the remaining payment method, without charging anything
+pub async fn get_split_payment_preview(
+    state: &SessionState,
+    payment_intent: &hyperswitch_domain_models::payments::PaymentIntent,
+) -> errors::RouterResult<SplitPaymentPreview> {
+    let balances = fetch_all_payment_method_balances_from_redis(state, &payment_intent.id)
+        .await
+        .attach_printable("Failed to retrieve payment method balances for split preview")?;
+
+    compute_split_payment_preview(
+        balances.values(),
+        payment_intent.amount_details.order_amount,
+        payment_intent.amount_details.currency,
+    )
+}
+
+/// Computes how the order amount would be split between the given balances and the remaining
+/// payment method
+fn compute_split_payment_preview<'a>(
+    balances: impl IntoIterator<Item = &'a domain::PaymentMethodBalance>,
+    order_amount: MinorUnit,
+    currency: common_enums::Currency,
+) -> errors::RouterResult<SplitPaymentPreview> {
+    let gift_card_balance = get_total_available_balance(balances, currency)?;
+    let gift_card_contribution = gift_card_balance.min(order_amount);
+
+    Ok(SplitPaymentPreview {
+        gift_card_balance,
+        gift_card_contribution,
+        remaining_amount: order_amount - gift_card_contribution,
+        currency,
+    })
+}
+
+pub async fn fetch_payment_methods_balances_from_redis(
+    state: &SessionState,
+    payment_intent_id: &id_type::GlobalPaymentId,
+    payment_methods: &[api_models::payments::BalanceCheckPaymentMethodData],
+) -> errors::RouterResult<HashMap<domain::PaymentMethodBalanceKey, domain::PaymentMethodBalance>> {
+    let balance_values =
+        fetch_all_payment_method_balances_from_redis(state, payment_intent_id).await?;
 
     payment_methods
         .iter()
         .map(|pm| {
             let api_models::payments::BalanceCheckPaymentMethodData::GiftCard(gift_card_data) = pm;
-            let pm_balance_key = domain::PaymentMethodBalanceKey {
-                payment_method_type: common_enums::PaymentMethod::GiftCard,
-                payment_method_subtype: gift_card_data.get_payment_method_type(),
-                payment_method_key: domain::GiftCardData::from(gift_card_data.clone())
-                    .get_payment_method_key()
-                    .change_context(errors::ApiErrorResponse::InvalidRequestData {
-                        message: "Unable to get unique key for payment method".to_string(),
-                    })?
-                    .expose(),
-            };
+            let pm_balance_key = get_gift_card_balance_key(gift_card_data)?;
             let redis_key = pm_balance_key.get_redis_key();
             let balance_value = balance_values.get(&redis_key).cloned().ok_or(
                 errors::ApiErrorResponse::GenericNotFoundError {
@@ -342,3 +435,181 @@ pub async fn fetch_payment_methods_balances_from_redis(
         })
         .collect::<errors::RouterResult<HashMap<_, _>>>()
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_pm_balance(balance: i64) -> domain::PaymentMethodBalance {
+        domain::PaymentMethodBalance {
+            balance: MinorUnit::new(balance),
+            currency: common_enums::Currency::USD,
+            transaction_limit: None,
+        }
+    }
+
+    #[test]
+    fn test_total_available_balance() {
+        let balances = [get_pm_balance(1000), get_pm_balance(2500)];
+        let total_balance =
+            get_total_available_balance(balances.iter(), common_enums::Currency::USD).unwrap();
+        assert_eq!(total_balance, MinorUnit::new(3500));
+    }
+
+    #[test]
+    fn test_total_available_balance_overflow() {
+        let balances = [get_pm_balance(i64::MAX), get_pm_balance(1)];
+        let error =
+            get_total_available_balance(balances.iter(), common_enums::Currency::USD).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::AmountOverflow { .. }
+        ));
+    }
+
+    #[test]
+    fn test_split_payment_preview_with_remaining_amount() {
+        let mut limited_balance = get_pm_balance(4000);
+        limited_balance.transaction_limit = Some(MinorUnit::new(2500));
+        let balances = [get_pm_balance(1000), limited_balance];
+
+        let preview = compute_split_payment_preview(
+            balances.iter(),
+            MinorUnit::new(6000),
+            common_enums::Currency::USD,
+        )
+        .unwrap();
+
+        assert_eq!(
+            preview,
+            SplitPaymentPreview {
+                gift_card_balance: MinorUnit::new(3500),
+                gift_card_contribution: MinorUnit::new(3500),
+                remaining_amount: MinorUnit::new(2500),
+                currency: common_enums::Currency::USD,
+            }
+        );
+    }
+
+    /// Records the `unclamped_remaining_amount` logged while it is the default subscriber
+    #[derive(Clone, Default)]
+    struct UnclampedRemainingAmountRecorder(std::sync::Arc<std::sync::Mutex<Vec<i64>>>);
+
+    struct UnclampedRemainingAmountVisitor<'a>(&'a mut Vec<i64>);
+
+    impl tracing::field::Visit for UnclampedRemainingAmountVisitor<'_> {
+        fn record_i64(&mut self, field: &tracing::field::Field, value: i64) {
+            if field.name() == "unclamped_remaining_amount" {
+                self.0.push(value);
+            }
+        }
+
+        fn record_debug(&mut self, _field: &tracing::field::Field, _value: &dyn std::fmt::Debug) {}
+    }
+
+    impl tracing::Subscriber for UnclampedRemainingAmountRecorder {
+        fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            tracing::span::Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &tracing::span::Id, _values: &tracing::span::Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {}
+
+        fn event(&self, event: &tracing::Event<'_>) {
+            let mut recorded = self.0.lock().unwrap();
+            event.record(&mut UnclampedRemainingAmountVisitor(&mut recorded));
+        }
+
+        fn enter(&self, _span: &tracing::span::Id) {}
+
+        fn exit(&self, _span: &tracing::span::Id) {}
+    }
+
+    fn get_remaining_amount_with_logs(
+        order_amount: MinorUnit,
+        total_balance: MinorUnit,
+    ) -> (MinorUnit, Vec<i64>) {
+        let recorder = UnclampedRemainingAmountRecorder::default();
+        let remaining_amount = tracing::subscriber::with_default(recorder.clone(), || {
+            get_remaining_amount(order_amount, total_balance)
+        });
+        let logged_amounts = recorder.0.lock().unwrap().clone();
+        (remaining_amount, logged_amounts)
+    }
+
+    #[test]
+    fn test_remaining_amount_logs_negative_remainder_when_balances_exceed_order() {
+        let (remaining_amount, logged_amounts) =
+            get_remaining_amount_with_logs(MinorUnit::new(3000), MinorUnit::new(4200));
+
+        assert_eq!(remaining_amount, MinorUnit::zero());
+        assert_eq!(logged_amounts, vec![-1200]);
+    }
+
+    #[test]
+    fn test_remaining_amount_when_order_exceeds_balances() {
+        let (remaining_amount, logged_amounts) =
+            get_remaining_amount_with_logs(MinorUnit::new(5000), MinorUnit::new(4200));
+
+        assert_eq!(remaining_amount, MinorUnit::new(800));
+        assert_eq!(logged_amounts, vec![800]);
+    }
+
+    #[test]
+    fn test_split_payment_preview_when_gift_cards_cover_order() {
+        let balances = [get_pm_balance(5000), get_pm_balance(3000)];
+
+        let preview = compute_split_payment_preview(
+            balances.iter(),
+            MinorUnit::new(6000),
+            common_enums::Currency::USD,
+        )
+        .unwrap();
+
+        assert_eq!(preview.gift_card_balance, MinorUnit::new(8000));
+        assert_eq!(preview.gift_card_contribution, MinorUnit::new(6000));
+        assert_eq!(preview.remaining_amount, MinorUnit::zero());
+    }
+
+    #[test]
+    fn test_apply_pm_data_skips_balances_in_other_currencies() {
+        let mut eur_balance = get_pm_balance(5000);
+        eur_balance.currency = common_enums::Currency::EUR;
+        let balances = [get_pm_balance(1000), eur_balance];
+
+        let resp = get_apply_pm_data_response(
+            balances.iter(),
+            MinorUnit::new(6000),
+            common_enums::Currency::USD,
+        )
+        .unwrap();
+
+        assert_eq!(resp.remaining_amount, MinorUnit::new(5000));
+        assert_eq!(resp.currency, common_enums::Currency::USD);
+        assert!(resp.requires_additional_pm_data);
+    }
+
+    #[test]
+    fn test_split_payment_preview_skips_balances_in_other_currencies() {
+        let mut eur_balance = get_pm_balance(5000);
+        eur_balance.currency = common_enums::Currency::EUR;
+        let balances = [get_pm_balance(1000), eur_balance];
+
+        let preview = compute_split_payment_preview(
+            balances.iter(),
+            MinorUnit::new(6000),
+            common_enums::Currency::USD,
+        )
+        .unwrap();
+
+        assert_eq!(preview.gift_card_balance, MinorUnit::new(1000));
+        assert_eq!(preview.gift_card_contribution, MinorUnit::new(1000));
+        assert_eq!(preview.remaining_amount, MinorUnit::new(5000));
+    }
+}
@@ -16,7 +16,7 @@ use hyperswitch_interfaces::secrets_interface::secret_state::RawSecret;
 use super::errors;
 use crate::{
     configs::settings,
-    core::{payment_methods, payments::helpers},
+    core::{payment_method_balance, payment_methods, payments::helpers},
     db::errors::StorageErrorExt,
     logger, routes,
     types::{self, api, domain, storage},
@@ -69,6 +69,13 @@ pub async fn list_payment_methods(
         None => None,
     };
 
+    let split_payment_preview = match req.include_split_preview {
+        Some(true) => {
+            Some(payment_method_balance::get_split_payment_preview(&state, &payment_intent).await?)
+        }
+        Some(false) | None => None,
+    };
+
     let response =
         FlattenedPaymentMethodsEnabled(hyperswitch_domain_models::merchant_connector_account::FlattenedPaymentMethodsEnabled::from_payment_connectors_list(payment_connector_accounts))
             .perform_filtering(
@@ -83,7 +90,7 @@ pub async fn list_payment_methods(
             .get_required_fields(RequiredFieldsInput::new(state.conf.required_fields.clone(), payment_intent.setup_future_usage))
             .perform_surcharge_calculation()
             .populate_pm_subtype_specific_data(&state.conf.bank_config)
-            .generate_response(customer_payment_methods);
+            .generate_response(customer_payment_methods, split_payment_preview);
 
     Ok(hyperswitch_domain_models::api::ApplicationResponse::Json(
         response,
@@ -466,6 +473,7 @@ impl RequiredFieldsAndSurchargeWithExtraInfoForEnabledPaymentMethodTypes {
         customer_payment_methods: Option<
             Vec<api_models::payment_methods::CustomerPaymentMethodResponseItem>,
         >,
+        split_payment_preview: Option<api_models::payments::SplitPaymentPreview>,
     ) -> api_models::payments::PaymentMethodListResponseForPayments {
         let response_payment_methods = self
             .0
@@ -485,6 +493,7 @@ impl RequiredFieldsAndSurchargeWithExtraInfoForEnabledPaymentMethodTypes {
         api_models::payments::PaymentMethodListResponseForPayments {
             payment_methods_enabled: response_payment_methods,
             customer_payment_methods,
+            split_payment_preview,
         }
     }
 }